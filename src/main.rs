@@ -1,10 +1,12 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::error::Error;
 use std::process::Command;
 use sysinfo::{System};
 use std::fs::File;
 use std::io::Write;
 use std::fs;
+use nvml_wrapper::Nvml;
+use sha2::{Digest, Sha256};
 
 
 #[derive(Debug)]
@@ -24,6 +26,92 @@ impl std::fmt::Display for PlatformError {
 
 impl Error for PlatformError {}
 
+#[derive(Debug, Deserialize)]
+struct MachineConfigFile {
+    machine: MachineSection,
+    #[serde(default)]
+    resource: ResourceOverrides,
+}
+
+#[derive(Debug, Deserialize)]
+struct MachineSection {
+    name: String,
+    description: String,
+    network: String,
+    #[serde(rename = "type")]
+    config_type: String,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct ResourceOverrides {
+    ram: Option<RamOverride>,
+    #[serde(default)]
+    disk: Vec<DiskOverride>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RamOverride {
+    size: Option<String>,
+    #[serde(rename = "type")]
+    ram_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DiskOverride {
+    name: String,
+    size: Option<String>,
+    #[serde(rename = "type")]
+    disk_type: Option<String>,
+}
+
+fn load_machine_config(path: &str) -> Result<Option<MachineConfigFile>, Box<dyn Error>> {
+    if !std::path::Path::new(path).exists() {
+        return Ok(None);
+    }
+
+    let settings = config::Config::builder()
+        .add_source(config::File::with_name(path))
+        .build()?;
+
+    Ok(Some(settings.try_deserialize()?))
+}
+
+fn parse_size_to_bytes(input: &str) -> Result<u64, Box<dyn Error>> {
+    let trimmed = input.trim();
+    let split_at = trimmed
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(trimmed.len());
+    let (num_part, suffix) = trimmed.split_at(split_at);
+
+    let value: f64 = num_part
+        .parse()
+        .map_err(|_| PlatformError::CommandFailed(format!("Invalid size value: {}", input)))?;
+
+    // Decimal SI suffixes (KB/MB/GB/TB) are powers of 1000; binary suffixes
+    // (K/KIB, M/MIB, ...) are powers of 1024. Don't conflate them, or a
+    // "500GB" disk gets reported as 536_870_912_000 bytes instead of
+    // 500_000_000_000.
+    let multiplier = match suffix.trim().to_uppercase().as_str() {
+        "" | "B" => 1u64,
+        "K" | "KIB" => 1024,
+        "KB" => 1000,
+        "M" | "MIB" => 1024u64.pow(2),
+        "MB" => 1000u64.pow(2),
+        "G" | "GIB" => 1024u64.pow(3),
+        "GB" => 1000u64.pow(3),
+        "T" | "TIB" => 1024u64.pow(4),
+        "TB" => 1000u64.pow(4),
+        other => {
+            return Err(Box::new(PlatformError::CommandFailed(format!(
+                "Unknown size suffix: {}",
+                other
+            ))))
+        }
+    };
+
+    Ok((value * multiplier as f64) as u64)
+}
+
 #[derive(Serialize)]
 struct ResourceConfig {
     name: String,
@@ -31,6 +119,7 @@ struct ResourceConfig {
     network: String,
     #[serde(rename = "type")]
     config_type: String,
+    fingerprint: String,
     config: Config,
 }
 
@@ -42,7 +131,7 @@ struct Config {
 #[derive(Serialize)]
 struct Resource {
     ram: Ram,
-    ssd: Ssd,
+    disks: Vec<Disk>,
     gpus: Vec<Gpu>,
     cpu: CpuSpec,
 }
@@ -55,15 +144,129 @@ struct Ram {
 }
 
 #[derive(Serialize)]
-struct Ssd {
+struct Disk {
+    name: String,
     size: u64,
     #[serde(rename = "type")]
-    ssd_type: String,
+    disk_type: String,
+    model: Option<String>,
+    serial: Option<String>,
+    health: Option<NvmeHealth>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct NvmeHealth {
+    // `nvme smart-log -o json` reports temperature in Kelvin; convert to
+    // Celsius on the way in so consumers don't have to know that.
+    #[serde(
+        rename(deserialize = "temperature"),
+        deserialize_with = "deserialize_kelvin_to_celsius"
+    )]
+    temperature_celsius: i64,
+    #[serde(rename(deserialize = "percent_used"))]
+    percentage_used: u64,
+    data_units_written: u64,
+    data_units_read: u64,
+    media_errors: u64,
+    power_on_hours: u64,
+}
+
+fn deserialize_kelvin_to_celsius<'de, D>(deserializer: D) -> Result<i64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let kelvin = i64::deserialize(deserializer)?;
+    Ok(kelvin - 273)
+}
+
+#[derive(Debug, Deserialize)]
+struct LsblkOutput {
+    blockdevices: Vec<LsblkDevice>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LsblkDevice {
+    name: String,
+    #[serde(rename = "type")]
+    device_type: String,
+    #[serde(deserialize_with = "deserialize_u64_flexible")]
+    size: u64,
+    tran: Option<String>,
+    #[serde(deserialize_with = "deserialize_bool_flexible")]
+    rota: bool,
+    model: Option<String>,
+    serial: Option<String>,
+}
+
+// util-linux >= 2.37 emits `rota`/`size` as native JSON bool/number; older
+// releases quote everything as a string. Accept either so `lsblk -J` parses
+// the same way across hosts.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BoolOrString {
+    Bool(bool),
+    String(String),
+}
+
+fn deserialize_bool_flexible<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    match BoolOrString::deserialize(deserializer)? {
+        BoolOrString::Bool(b) => Ok(b),
+        BoolOrString::String(s) => Ok(s == "1" || s.eq_ignore_ascii_case("true")),
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NumOrString {
+    Num(u64),
+    String(String),
+}
+
+fn deserialize_u64_flexible<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    match NumOrString::deserialize(deserializer)? {
+        NumOrString::Num(n) => Ok(n),
+        NumOrString::String(s) => s.parse().map_err(serde::de::Error::custom),
+    }
+}
+
+#[derive(Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum GpuVendor {
+    Amd,
+    Nvidia,
+    Intel,
+    Unknown,
+}
+
+const VENDOR_ID_AMD: u32 = 0x1002;
+const VENDOR_ID_NVIDIA: u32 = 0x10de;
+const VENDOR_ID_INTEL: u32 = 0x8086;
+
+fn vendor_from_id(id: u32) -> GpuVendor {
+    match id {
+        VENDOR_ID_AMD => GpuVendor::Amd,
+        VENDOR_ID_NVIDIA => GpuVendor::Nvidia,
+        VENDOR_ID_INTEL => GpuVendor::Intel,
+        _ => GpuVendor::Unknown,
+    }
 }
 
 #[derive(Serialize)]
 struct Gpu {
     model: String,
+    vendor: GpuVendor,
+    vram_bytes: Option<u64>,
+    driver_version: Option<String>,
+    cuda_version: Option<String>,
+    pci_bus_id: Option<String>,
+    pci_id: Option<u32>,
+    uuid: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -75,6 +278,68 @@ struct CpuSpec {
 struct CpuSpecs {
     cores: u32,
     clock_rate: u64,
+    model: String,
+    vendor: String,
+    features: Vec<String>,
+}
+
+#[cfg(target_arch = "x86_64")]
+mod cpuid {
+    use std::arch::x86_64::{__cpuid, __cpuid_count};
+
+    pub fn vendor_id() -> String {
+        let result = __cpuid(0);
+        let mut bytes = Vec::with_capacity(12);
+        bytes.extend_from_slice(&result.ebx.to_le_bytes());
+        bytes.extend_from_slice(&result.edx.to_le_bytes());
+        bytes.extend_from_slice(&result.ecx.to_le_bytes());
+        String::from_utf8_lossy(&bytes).trim().to_string()
+    }
+
+    pub fn brand_string() -> Option<String> {
+        let max_extended = __cpuid(0x80000000).eax;
+        if max_extended < 0x80000004 {
+            return None;
+        }
+
+        let mut bytes = Vec::with_capacity(48);
+        for leaf in 0x80000002..=0x80000004 {
+            let result = __cpuid(leaf);
+            for reg in [result.eax, result.ebx, result.ecx, result.edx] {
+                bytes.extend_from_slice(&reg.to_le_bytes());
+            }
+        }
+
+        Some(
+            String::from_utf8_lossy(&bytes)
+                .trim_matches(char::from(0))
+                .trim()
+                .to_string(),
+        )
+    }
+
+    pub fn features() -> Vec<String> {
+        let mut features = Vec::new();
+
+        let max_standard = __cpuid(0).eax;
+        if max_standard < 7 {
+            return features;
+        }
+
+        let extended = __cpuid_count(7, 0);
+
+        if extended.ebx & (1 << 5) != 0 {
+            features.push("AVX2".to_string());
+        }
+        if extended.ebx & (1 << 16) != 0 {
+            features.push("AVX512F".to_string());
+        }
+        if extended.ecx & (1 << 1) != 0 {
+            features.push("AVX512VBMI".to_string());
+        }
+
+        features
+    }
 }
 
 fn check_platform() -> Result<(), Box<dyn Error>> {
@@ -84,13 +349,36 @@ fn check_platform() -> Result<(), Box<dyn Error>> {
         )));
     }
 
-    return Ok(());
+    Ok(())
 }
 
-fn get_gpu_info() -> Result<Vec<String>, Box<dyn Error>> {
-    check_platform()?;
+fn get_nvml_gpu_info() -> Result<Vec<Gpu>, Box<dyn Error>> {
+    let nvml = Nvml::init()?;
+    let driver_version = nvml.sys_driver_version().ok();
+    let cuda_version = nvml
+        .sys_cuda_driver_version()
+        .ok()
+        .map(|v| format!("{}.{}", v / 1000, (v % 1000) / 10));
+
+    let mut gpus = Vec::new();
+    for index in 0..nvml.device_count()? {
+        let device = nvml.device_by_index(index)?;
+        gpus.push(Gpu {
+            model: device.name()?,
+            vendor: GpuVendor::Nvidia,
+            vram_bytes: device.memory_info().ok().map(|m| m.total),
+            driver_version: driver_version.clone(),
+            cuda_version: cuda_version.clone(),
+            pci_bus_id: device.pci_info().ok().map(|p| p.bus_id),
+            pci_id: None,
+            uuid: device.uuid().ok(),
+        });
+    }
+
+    Ok(gpus)
+}
 
-    // First try with lspci
+fn get_lspci_gpu_info() -> Result<Vec<Gpu>, Box<dyn Error>> {
     let output = Command::new("lspci")
         .args(["-v"])
         .output()
@@ -116,7 +404,16 @@ fn get_gpu_info() -> Result<Vec<String>, Box<dyn Error>> {
                 {
                     let nvidia_str = String::from_utf8_lossy(&nvidia_output.stdout);
                     if nvidia_output.status.success() && !nvidia_str.trim().is_empty() {
-                        gpus.push(nvidia_str.trim().to_string());
+                        gpus.push(Gpu {
+                            model: nvidia_str.trim().to_string(),
+                            vendor: GpuVendor::Nvidia,
+                            vram_bytes: None,
+                            driver_version: None,
+                            cuda_version: None,
+                            pci_bus_id: None,
+                            pci_id: None,
+                            uuid: None,
+                        });
                         continue;
                     }
                 }
@@ -129,17 +426,121 @@ fn get_gpu_info() -> Result<Vec<String>, Box<dyn Error>> {
                 .unwrap_or("Unknown GPU")
                 .trim()
                 .to_string();
-            gpus.push(gpu_model);
+            let vendor = if gpu_model.contains("AMD") || gpu_model.contains("ATI") {
+                GpuVendor::Amd
+            } else if gpu_model.contains("Intel") {
+                GpuVendor::Intel
+            } else {
+                GpuVendor::Unknown
+            };
+            gpus.push(Gpu {
+                model: gpu_model,
+                vendor,
+                vram_bytes: None,
+                driver_version: None,
+                cuda_version: None,
+                pci_bus_id: None,
+                pci_id: None,
+                uuid: None,
+            });
         }
     }
 
     if gpus.is_empty() {
-        gpus.push("Unknown GPU".to_string());
+        gpus.push(Gpu {
+            model: "Unknown GPU".to_string(),
+            vendor: GpuVendor::Unknown,
+            vram_bytes: None,
+            driver_version: None,
+            cuda_version: None,
+            pci_bus_id: None,
+            pci_id: None,
+            uuid: None,
+        });
+    }
+
+    Ok(gpus)
+}
+
+fn get_opencl_gpu_info(skip_nvidia: bool) -> Result<Vec<Gpu>, Box<dyn Error>> {
+    let mut gpus = Vec::new();
+
+    for platform in opencl3::platform::get_platforms()? {
+        let device_ids =
+            opencl3::device::get_device_ids(platform.id(), opencl3::device::CL_DEVICE_TYPE_GPU)
+                .map_err(|code| {
+                    PlatformError::CommandFailed(format!(
+                        "OpenCL get_device_ids failed with code {}",
+                        code
+                    ))
+                })?;
+
+        for id in device_ids {
+            let device = opencl3::device::Device::new(id);
+            let vendor = vendor_from_id(device.vendor_id()?);
+
+            // NVIDIA devices are reported via NVML when it's available;
+            // only skip them here if NVML actually succeeded, otherwise an
+            // NVML init failure would silently drop the card entirely.
+            if skip_nvidia && vendor == GpuVendor::Nvidia {
+                continue;
+            }
+
+            let pci_id = match vendor {
+                GpuVendor::Amd => device
+                    .topology_amd()
+                    .ok()
+                    .map(|t| ((t.bus as u32) << 8) | t.device as u32),
+                GpuVendor::Intel => device
+                    .pcibusinfokhr_intel()
+                    .ok()
+                    .map(|p| (p.pci_bus << 8) | p.pci_device),
+                _ => None,
+            };
+
+            let uuid = device
+                .uuid_khr()
+                .ok()
+                .map(|bytes| bytes.iter().map(|b| format!("{:02x}", b)).collect());
+
+            gpus.push(Gpu {
+                model: device.name().unwrap_or_else(|_| "Unknown GPU".to_string()),
+                vendor,
+                vram_bytes: device.global_mem_size().ok(),
+                driver_version: device.driver_version().ok(),
+                cuda_version: None,
+                pci_bus_id: None,
+                pci_id,
+                uuid,
+            });
+        }
     }
 
     Ok(gpus)
 }
 
+fn get_gpu_info() -> Result<Vec<Gpu>, Box<dyn Error>> {
+    check_platform()?;
+
+    // Prefer NVML for structured NVIDIA telemetry and OpenCL for AMD/Intel
+    // identification; fall back to the lspci scan when neither turns up a
+    // GPU. Unlike NVML (which dlopens libnvidia-ml.so lazily and degrades
+    // to `Err` when it's missing), opencl3 links libOpenCL.so.1 at build
+    // time, so an OpenCL ICD loader is a hard runtime requirement for this
+    // binary to start at all on any host, not just an optional source of
+    // AMD/Intel GPU info.
+    let nvml_result = get_nvml_gpu_info();
+    let nvml_succeeded = nvml_result.is_ok();
+    let mut gpus = nvml_result.unwrap_or_default();
+    gpus.extend(get_opencl_gpu_info(nvml_succeeded).unwrap_or_default());
+
+    if gpus.is_empty() {
+        get_lspci_gpu_info()
+    } else {
+        Ok(gpus)
+    }
+}
+
 fn get_ram_type() -> Result<String, Box<dyn Error>> {
     check_platform()?;
 
@@ -170,12 +571,68 @@ fn get_ram_type() -> Result<String, Box<dyn Error>> {
     Ok("Unknown".to_string())
 }
 
-fn get_storage_info() -> Result<(u64, String), Box<dyn Error>> {
+fn nvme_cli_available() -> bool {
+    Command::new("nvme")
+        .arg("version")
+        .output()
+        .map(|output| output.status.success())
+        .unwrap_or(false)
+}
+
+fn get_nvme_health(device_name: &str) -> Result<NvmeHealth, Box<dyn Error>> {
+    let output = Command::new("sudo")
+        .args([
+            "nvme",
+            "smart-log",
+            &format!("/dev/{}", device_name),
+            "-o",
+            "json",
+        ])
+        .output()
+        .map_err(|e| {
+            PlatformError::CommandFailed(format!("Failed to execute nvme smart-log: {}", e))
+        })?;
+
+    if !output.status.success() {
+        return Err(Box::new(PlatformError::CommandFailed(format!(
+            "nvme smart-log failed for /dev/{} (device may not support the log page)",
+            device_name
+        ))));
+    }
+
+    let output_str = String::from_utf8_lossy(&output.stdout);
+    let health: NvmeHealth = serde_json::from_str(&output_str).map_err(|e| {
+        PlatformError::CommandFailed(format!("Failed to parse nvme smart-log output: {}", e))
+    })?;
+
+    Ok(health)
+}
+
+fn classify_storage(tran: Option<&str>, non_rotational: bool) -> String {
+    match (tran, non_rotational) {
+        (Some("nvme"), _) => "NVMe".to_string(),
+        (Some("sata"), true) => "SATA SSD".to_string(),
+        (Some("sata"), false) => "SATA HDD".to_string(),
+        (Some(other), true) => format!("{} SSD", other.to_uppercase()),
+        (Some(other), false) => format!("{} HDD", other.to_uppercase()),
+        (None, true) => "SSD".to_string(),
+        (None, false) => "HDD".to_string(),
+    }
+}
+
+fn get_storage_info() -> Result<Vec<Disk>, Box<dyn Error>> {
     check_platform()?;
 
-    // Use lsblk with size information
+    // Use lsblk's JSON output so columns can't shift under us, and so we
+    // can report every drive instead of just the largest.
     let output = Command::new("lsblk")
-        .args(["-d", "-o", "NAME,TYPE,SIZE,TRAN", "--bytes"]) // --bytes for exact size
+        .args([
+            "-d",
+            "-b",
+            "-J",
+            "-o",
+            "NAME,TYPE,SIZE,TRAN,ROTA,MODEL,SERIAL",
+        ])
         .output()
         .map_err(|e| PlatformError::CommandFailed(format!("Failed to execute lsblk: {}", e)))?;
 
@@ -186,111 +643,215 @@ fn get_storage_info() -> Result<(u64, String), Box<dyn Error>> {
     }
 
     let output_str = String::from_utf8_lossy(&output.stdout);
-    let mut largest_device_size = 0u64;
-    let mut storage_type = String::from("Unknown");
-
-    // Try to find NVMe devices first
-    for line in output_str.lines().skip(1) {
-        // skip header line
-        let parts: Vec<&str> = line.split_whitespace().collect();
-        if parts.len() >= 3 {
-            let device_name = parts[0];
-            let size_str = parts[2];
-            let size = size_str.parse::<u64>().unwrap_or(0);
-
-            if size > largest_device_size {
-                largest_device_size = size;
-
-                if device_name.starts_with("nvme") {
-                    // Try to get NVMe generation
-                    let nvme_info = Command::new("sudo").args(["nvme", "list"]).output();
-
-                    if let Ok(nvme_output) = nvme_info {
-                        let nvme_str = String::from_utf8_lossy(&nvme_output.stdout);
-                        if nvme_str.contains("PCIe 4.0") {
-                            storage_type = "NVMeGen4".to_string();
-                        } else if nvme_str.contains("PCIe 3.0") {
-                            storage_type = "NVMeGen3".to_string();
-                        } else {
-                            storage_type = "NVMe".to_string();
-                        }
-                    }
-                } else if parts.get(3).map_or(false, |&t| t == "sata") {
-                    // Check if it's an SSD for SATA devices
-                    let smart_info = Command::new("sudo")
-                        .args(["smartctl", "-i", &format!("/dev/{}", device_name)])
-                        .output();
-
-                    if let Ok(smart_output) = smart_info {
-                        let smart_str = String::from_utf8_lossy(&smart_output.stdout);
-                        if smart_str.contains("Solid State Device") {
-                            storage_type = "SATA SSD".to_string();
-                        } else {
-                            storage_type = "SATA HDD".to_string();
-                        }
-                    }
-                }
+    let parsed: LsblkOutput = serde_json::from_str(&output_str).map_err(|e| {
+        PlatformError::CommandFailed(format!("Failed to parse lsblk output: {}", e))
+    })?;
+
+    let mut disks: Vec<Disk> = parsed
+        .blockdevices
+        .into_iter()
+        .filter(|device| device.device_type == "disk")
+        .map(|device| {
+            let non_rotational = !device.rota;
+            Disk {
+                disk_type: classify_storage(device.tran.as_deref(), non_rotational),
+                name: device.name,
+                size: device.size,
+                model: device.model,
+                serial: device.serial,
+                health: None,
+            }
+        })
+        .collect();
+
+    // Enrich NVMe entries with a generation label and SMART health on top of
+    // the parsed list.
+    if disks.iter().any(|disk| disk.disk_type == "NVMe") && nvme_cli_available() {
+        if let Ok(nvme_output) = Command::new("sudo").args(["nvme", "list"]).output() {
+            let nvme_str = String::from_utf8_lossy(&nvme_output.stdout);
+            let generation = if nvme_str.contains("PCIe 4.0") {
+                "NVMeGen4"
+            } else if nvme_str.contains("PCIe 3.0") {
+                "NVMeGen3"
+            } else {
+                "NVMe"
+            };
+
+            for disk in disks.iter_mut().filter(|disk| disk.disk_type == "NVMe") {
+                disk.disk_type = generation.to_string();
+                disk.health = get_nvme_health(&disk.name).ok();
             }
         }
     }
 
-    if largest_device_size == 0 {
+    if disks.is_empty() {
         return Err(Box::new(PlatformError::CommandFailed(
-            "Could not determine storage size".to_string(),
+            "Could not determine storage devices".to_string(),
         )));
     }
 
-    Ok((largest_device_size, storage_type))
+    Ok(disks)
+}
+
+fn compute_fingerprint(resource: &Resource) -> String {
+    // Device enumeration order from lspci/lsblk isn't stable across boots,
+    // so every identifier is normalized (lowercased) and the whole set is
+    // sorted before hashing to keep the fingerprint reproducible.
+    let mut parts: Vec<String> = Vec::new();
+
+    parts.push(format!(
+        "ram:{}:{}",
+        resource.ram.size,
+        resource.ram.ram_type.to_lowercase()
+    ));
+
+    for gpu in &resource.gpus {
+        let identifier = gpu
+            .uuid
+            .clone()
+            .or_else(|| gpu.pci_bus_id.clone())
+            .or_else(|| gpu.pci_id.map(|id| format!("{:x}", id)))
+            .unwrap_or_else(|| gpu.model.clone());
+        parts.push(format!("gpu:{}", identifier.to_lowercase()));
+    }
+
+    for disk in &resource.disks {
+        let identifier = disk.serial.clone().unwrap_or_else(|| disk.name.clone());
+        parts.push(format!("disk:{}", identifier.to_lowercase()));
+    }
+
+    parts.push(format!(
+        "cpu:{}:{}",
+        resource.cpu.specs.vendor.to_lowercase(),
+        resource.cpu.specs.model.to_lowercase()
+    ));
+
+    parts.sort();
+
+    let mut hasher = Sha256::new();
+    for part in &parts {
+        hasher.update(part.as_bytes());
+        hasher.update(b"\n");
+    }
+
+    format!("{:x}", hasher.finalize())
+}
+
+fn get_cpu_identity(sys: &System) -> (String, String, Vec<String>) {
+    #[cfg(target_arch = "x86_64")]
+    {
+        let model = cpuid::brand_string().unwrap_or_else(|| {
+            sys.cpus()
+                .first()
+                .map(|cpu| cpu.brand().to_string())
+                .unwrap_or_else(|| "Unknown".to_string())
+        });
+        (model, cpuid::vendor_id(), cpuid::features())
+    }
+
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        let model = sys
+            .cpus()
+            .first()
+            .map(|cpu| cpu.brand().to_string())
+            .unwrap_or_else(|| "Unknown".to_string());
+        let vendor = sys
+            .cpus()
+            .first()
+            .map(|cpu| cpu.vendor_id().to_string())
+            .unwrap_or_else(|| "Unknown".to_string());
+        (model, vendor, Vec::new())
+    }
 }
 
-fn get_system_info() -> Result<ResourceConfig, Box<dyn Error>> {
+fn get_system_info(machine_config: Option<&MachineConfigFile>) -> Result<ResourceConfig, Box<dyn Error>> {
     let mut sys = System::new();
     sys.refresh_memory();
     sys.refresh_cpu();
 
     let ram_type = get_ram_type()?;
-    let (storage_size, storage_type) = get_storage_info()?;
-    let gpu_models = get_gpu_info()?;
+    let mut disks = get_storage_info()?;
+    let gpus = get_gpu_info()?;
 
     // CPU info - Convert MHz to Hz
     let cpu_cores = sys.cpus().len() as u32;
     let cpu_frequency = sys
         .cpus()
         .first()
-        .map(|cpu| cpu.frequency() as u64 * 1_000_000)
+        .map(|cpu| cpu.frequency() * 1_000_000)
         .unwrap_or(0);
+    let (cpu_model, cpu_vendor, cpu_features) = get_cpu_identity(&sys);
 
-    let config = ResourceConfig {
-        name: String::from("example"),
-        description: String::from("Configuration"),
-        network: String::from("dev"),
-        config_type: String::from("operator"),
-        config: Config {
-            resource: Resource {
-                ram: Ram {
-                    size: sys.total_memory(),
-                    ram_type,
-                },
-                ssd: Ssd {
-                    size: storage_size, // 1TB in bytes
-                    ssd_type: storage_type,
-                },
-                gpus: gpu_models.into_iter().map(|model| Gpu { model }).collect(),
-                cpu: CpuSpec {
-                    specs: CpuSpecs {
-                        cores: cpu_cores,
-                        clock_rate: cpu_frequency,
-                    },
-                },
+    let mut ram = Ram {
+        size: sys.total_memory(),
+        ram_type,
+    };
+
+    let mut name = String::from("example");
+    let mut description = String::from("Configuration");
+    let mut network = String::from("dev");
+    let mut config_type = String::from("operator");
+
+    if let Some(machine_config) = machine_config {
+        name = machine_config.machine.name.clone();
+        description = machine_config.machine.description.clone();
+        network = machine_config.machine.network.clone();
+        config_type = machine_config.machine.config_type.clone();
+
+        if let Some(ram_override) = &machine_config.resource.ram {
+            if let Some(size) = &ram_override.size {
+                ram.size = parse_size_to_bytes(size)?;
+            }
+            if let Some(ram_type) = &ram_override.ram_type {
+                ram.ram_type = ram_type.clone();
+            }
+        }
+
+        for disk_override in &machine_config.resource.disk {
+            if let Some(disk) = disks.iter_mut().find(|d| d.name == disk_override.name) {
+                if let Some(size) = &disk_override.size {
+                    disk.size = parse_size_to_bytes(size)?;
+                }
+                if let Some(disk_type) = &disk_override.disk_type {
+                    disk.disk_type = disk_type.clone();
+                }
+            }
+        }
+    }
+
+    let resource = Resource {
+        ram,
+        disks,
+        gpus,
+        cpu: CpuSpec {
+            specs: CpuSpecs {
+                cores: cpu_cores,
+                clock_rate: cpu_frequency,
+                model: cpu_model,
+                vendor: cpu_vendor,
+                features: cpu_features,
             },
         },
     };
 
+    let fingerprint = compute_fingerprint(&resource);
+
+    let config = ResourceConfig {
+        name,
+        description,
+        network,
+        config_type,
+        fingerprint,
+        config: Config { resource },
+    };
+
     Ok(config)
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let system_info = get_system_info()?;
+    let machine_config = load_machine_config("resources.toml")?;
+    let system_info = get_system_info(machine_config.as_ref())?;
 
     let json = serde_json::to_string_pretty(&system_info)?;
     println!("{}", json);